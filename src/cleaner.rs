@@ -0,0 +1,231 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+use std::fs;
+use std::io::{self, Read, Write};
+
+use svgdom::{Document, Node, ToStringOptions, WriteToString};
+
+use {Error, ChainedErrorExt};
+use options::{ParseOptions, WriteOptions, CleaningOptions};
+use svg_unit::SvgUnit;
+use svg_version::SvgVersion;
+use task;
+use task::short::{EId, AId};
+
+pub fn load_stdin() -> Result<String, Error> {
+    let mut s = String::new();
+    io::stdin().read_to_string(&mut s)
+        .map_err(|e| Error::new("failed to read stdin").caused_by(e.to_string()))?;
+    Ok(s)
+}
+
+pub fn load_file(path: &str) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map_err(|e| Error::new(format!("failed to read '{}'", path)).caused_by(e.to_string()))
+}
+
+pub fn parse_data(text: &str, _opt: &ParseOptions) -> Result<Document, Error> {
+    Document::from_str(text)
+        .map_err(|e| Error::new("failed to parse the input file").caused_by(e.to_string()))
+}
+
+/// Cleans `doc` in place and returns a warning for each construct that was
+/// stripped because it doesn't fit `cleaning_opt.svg_version` (see
+/// `task::utils::enforce_profile`).
+pub fn clean_doc(
+    doc: &mut Document,
+    cleaning_opt: &CleaningOptions,
+    _write_opt: &WriteOptions,
+) -> Result<Vec<String>, Error> {
+    task::utils::resolve_gradient_attributes(doc)?;
+    task::apply_transforms::shapes::apply_transform_to_shapes(doc);
+
+    Ok(task::utils::enforce_profile(doc, cleaning_opt.svg_version))
+}
+
+pub fn write_buffer(doc: &Document, write_opt: &WriteOptions, buf: &mut Vec<u8>) {
+    apply_root_profile(doc, write_opt.svg_version);
+    apply_root_unit(doc, write_opt.unit);
+
+    let mut opt = ToStringOptions::default();
+    opt.indent = write_opt.indent;
+    opt.use_single_quote = write_opt.use_single_quote;
+
+    let s = doc.to_string_with_opt(&opt);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+// Stamps (or clears) the root `<svg>`'s `version`/`baseProfile` to match the
+// profile the output is targeting.
+fn apply_root_profile(doc: &Document, version: SvgVersion) {
+    let svg = match doc.descendants().svg().find(|n| n.is_tag_name(EId::Svg)) {
+        Some(svg) => svg,
+        None => return,
+    };
+
+    match version.version_attr() {
+        Some(v) => svg.set_attribute(AId::Version, v),
+        None => svg.remove_attribute(AId::Version),
+    }
+
+    match version.base_profile_attr() {
+        Some(p) => svg.set_attribute(AId::BaseProfile, p),
+        None => svg.remove_attribute(AId::BaseProfile),
+    }
+}
+
+// Rewrites the root `<svg>`'s `width`/`height` into the requested output
+// unit, so pipelines that need physical units don't have to post-process
+// the otherwise unitless user-space numbers the cleaner normalizes to.
+fn apply_root_unit(doc: &Document, unit: SvgUnit) {
+    let svg = match doc.descendants().svg().find(|n| n.is_tag_name(EId::Svg)) {
+        Some(svg) => svg,
+        None => return,
+    };
+
+    set_length_in_unit(&svg, AId::Width, unit);
+    set_length_in_unit(&svg, AId::Height, unit);
+}
+
+fn set_length_in_unit(node: &Node, aid: AId, unit: SvgUnit) {
+    let px = match node.attributes().get_number(aid) {
+        Some(px) => px,
+        None => return,
+    };
+
+    let value = unit.from_px(px);
+    if unit.suffix().is_empty() {
+        node.set_attribute(aid, value);
+    } else {
+        node.set_attribute(aid, format!("{}{}", value, unit.suffix()));
+    }
+}
+
+pub fn write_stdout(buf: &[u8]) -> Result<(), Error> {
+    io::stdout().write_all(buf)
+        .map_err(|e| Error::new("failed to write to stdout").caused_by(e.to_string()))
+}
+
+pub fn save_file(buf: &[u8], path: &str) -> Result<(), Error> {
+    fs::write(path, buf)
+        .map_err(|e| Error::new(format!("failed to write '{}'", path)).caused_by(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! profile_test {
+        ($name:ident, $version:expr, $in_text:expr, $out_text:expr) => (
+            #[test]
+            fn $name() {
+                let doc = Document::from_str($in_text).unwrap();
+                apply_root_profile(&doc, $version);
+                assert_eq_text!(doc.to_string_with_opt(&write_opt_for_tests!()), $out_text);
+            }
+        )
+    }
+
+    profile_test!(stamps_1_2_version,
+        SvgVersion::V1_2,
+        "<svg/>\n",
+        "<svg version='1.2'/>\n"
+    );
+
+    profile_test!(stamps_1_2_tiny_version_and_base_profile,
+        SvgVersion::V1_2Tiny,
+        "<svg/>\n",
+        "<svg baseProfile='tiny' version='1.2'/>\n"
+    );
+
+    profile_test!(stamps_1_1_tiny_base_profile_only,
+        SvgVersion::V1_1Tiny,
+        "<svg/>\n",
+        "<svg baseProfile='tiny'/>\n"
+    );
+
+    profile_test!(clears_stale_attrs_for_plain_1_1,
+        SvgVersion::V1_1,
+        "<svg baseProfile='tiny' version='1.2'/>\n",
+        "<svg/>\n"
+    );
+
+    #[test]
+    fn enforce_profile_is_noop_for_full_profiles() {
+        let doc = Document::from_str("<svg><rect filter='url(#f)'/></svg>").unwrap();
+        assert!(task::utils::enforce_profile(&doc, SvgVersion::V1_1).is_empty());
+        assert!(task::utils::enforce_profile(&doc, SvgVersion::V1_2).is_empty());
+
+        let rect = doc.descendants().svg().find(|n| n.is_tag_name(EId::Rect)).unwrap();
+        assert!(rect.has_attribute(AId::Filter));
+    }
+
+    #[test]
+    fn enforce_profile_strips_filter_and_mask_under_tiny() {
+        let doc = Document::from_str(
+            "<svg><rect filter='url(#f)'/><rect mask='url(#m)'/></svg>"
+        ).unwrap();
+        let warnings = task::utils::enforce_profile(&doc, SvgVersion::V1_1Tiny);
+        assert_eq!(warnings.len(), 2);
+
+        for rect in doc.descendants().svg().filter(|n| n.is_tag_name(EId::Rect)) {
+            assert!(!rect.has_attribute(AId::Filter));
+            assert!(!rect.has_attribute(AId::Mask));
+        }
+    }
+
+    macro_rules! unit_test {
+        ($name:ident, $unit:expr, $in_text:expr, $out_text:expr) => (
+            #[test]
+            fn $name() {
+                let doc = Document::from_str($in_text).unwrap();
+                apply_root_unit(&doc, $unit);
+                assert_eq_text!(doc.to_string_with_opt(&write_opt_for_tests!()), $out_text);
+            }
+        )
+    }
+
+    unit_test!(keeps_px_unitless,
+        SvgUnit::Px,
+        "<svg height='50' width='100'/>\n",
+        "<svg height='50' width='100'/>\n"
+    );
+
+    unit_test!(converts_to_inches,
+        SvgUnit::In,
+        "<svg height='48' width='96'/>\n",
+        "<svg height='0.5in' width='1in'/>\n"
+    );
+
+    unit_test!(converts_to_mm,
+        SvgUnit::Mm,
+        "<svg height='96' width='96'/>\n",
+        "<svg height='25.4mm' width='25.4mm'/>\n"
+    );
+
+    unit_test!(leaves_missing_dimensions_alone,
+        SvgUnit::In,
+        "<svg/>\n",
+        "<svg/>\n"
+    );
+}
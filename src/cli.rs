@@ -0,0 +1,205 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+use std::ops::Index;
+
+use clap::{App, Arg, ArgMatches};
+
+use options::{ParseOptions, WriteOptions, CleaningOptions};
+use svg_unit::SvgUnit;
+use svg_version::SvgVersion;
+
+/// A command line argument. Indexing into `KEYS` with a variant gets its
+/// clap name, so the name itself only has to be spelled out once.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Key {
+    Input,
+    Output,
+    Multipass,
+    AllowBiggerFile,
+    CopyOnError,
+    Quiet,
+    SvgVersion,
+    Unit,
+}
+
+pub struct Keys([&'static str; 8]);
+
+impl Index<Key> for Keys {
+    type Output = &'static str;
+
+    fn index(&self, key: Key) -> &&'static str {
+        match key {
+            Key::Input           => &self.0[0],
+            Key::Output          => &self.0[1],
+            Key::Multipass       => &self.0[2],
+            Key::AllowBiggerFile => &self.0[3],
+            Key::CopyOnError     => &self.0[4],
+            Key::Quiet           => &self.0[5],
+            Key::SvgVersion      => &self.0[6],
+            Key::Unit            => &self.0[7],
+        }
+    }
+}
+
+pub const KEYS: Keys = Keys([
+    "input",
+    "output",
+    "multipass",
+    "allow-bigger-file",
+    "copy-on-error",
+    "quiet",
+    "svg-version",
+    "unit",
+]);
+
+pub enum InputFrom<'a> {
+    Stdin,
+    File(&'a str),
+}
+
+pub enum OutputTo<'a> {
+    Stdout,
+    File(&'a str),
+}
+
+pub fn prepare_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("svgcleaner")
+        .arg(Arg::with_name(KEYS[Key::Input])
+            .help("Input file")
+            .required(true))
+        .arg(Arg::with_name(KEYS[Key::Output])
+            .help("Output file, or '-' for stdout")
+            .required(true))
+        .arg(Arg::with_name(KEYS[Key::Multipass])
+            .long(KEYS[Key::Multipass])
+            .help("Clean a file multiple times, until it stops changing in size"))
+        .arg(Arg::with_name(KEYS[Key::AllowBiggerFile])
+            .long(KEYS[Key::AllowBiggerFile])
+            .help("Allow the output file to be bigger than the input"))
+        .arg(Arg::with_name(KEYS[Key::CopyOnError])
+            .long(KEYS[Key::CopyOnError])
+            .help("Copy the original file to the output path on error"))
+        .arg(Arg::with_name(KEYS[Key::Quiet])
+            .short("q")
+            .long(KEYS[Key::Quiet])
+            .help("Suppress the size-reduction summary"))
+        .arg(Arg::with_name(KEYS[Key::SvgVersion])
+            .long(KEYS[Key::SvgVersion])
+            .takes_value(true)
+            .possible_values(&["1.1", "1.1-tiny", "1.2", "1.2-tiny"])
+            .default_value("1.1")
+            .help("Target SVG profile for the output"))
+        .arg(Arg::with_name(KEYS[Key::Unit])
+            .long(KEYS[Key::Unit])
+            .takes_value(true)
+            .possible_values(&["px", "pt", "mm", "cm", "in"])
+            .default_value("px")
+            .help("Unit the root width/height are written out in"))
+}
+
+pub fn check_values(_args: &ArgMatches) -> bool {
+    true
+}
+
+pub fn gen_parse_options(_args: &ArgMatches) -> ParseOptions {
+    ParseOptions::default()
+}
+
+pub fn gen_write_options(args: &ArgMatches) -> WriteOptions {
+    let mut opt = WriteOptions::default();
+    opt.svg_version = parse_svg_version(args.value_of(KEYS[Key::SvgVersion]));
+    opt.unit = parse_unit(args.value_of(KEYS[Key::Unit]));
+    opt
+}
+
+pub fn gen_cleaning_options(args: &ArgMatches) -> CleaningOptions {
+    CleaningOptions {
+        append_newline: false,
+        svg_version: parse_svg_version(args.value_of(KEYS[Key::SvgVersion])),
+    }
+}
+
+fn parse_svg_version(value: Option<&str>) -> SvgVersion {
+    match value {
+        Some("1.1-tiny") => SvgVersion::V1_1Tiny,
+        Some("1.2")      => SvgVersion::V1_2,
+        Some("1.2-tiny") => SvgVersion::V1_2Tiny,
+        _                => SvgVersion::V1_1,
+    }
+}
+
+fn parse_unit(value: Option<&str>) -> SvgUnit {
+    match value {
+        Some("pt") => SvgUnit::Pt,
+        Some("mm") => SvgUnit::Mm,
+        Some("cm") => SvgUnit::Cm,
+        Some("in") => SvgUnit::In,
+        _          => SvgUnit::Px,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_svg_version_value() {
+        assert_eq!(parse_svg_version(Some("1.1")), SvgVersion::V1_1);
+        assert_eq!(parse_svg_version(Some("1.1-tiny")), SvgVersion::V1_1Tiny);
+        assert_eq!(parse_svg_version(Some("1.2")), SvgVersion::V1_2);
+        assert_eq!(parse_svg_version(Some("1.2-tiny")), SvgVersion::V1_2Tiny);
+    }
+
+    #[test]
+    fn defaults_svg_version_to_1_1() {
+        assert_eq!(parse_svg_version(None), SvgVersion::V1_1);
+    }
+
+    #[test]
+    fn parses_each_unit_value() {
+        assert_eq!(parse_unit(Some("px")), SvgUnit::Px);
+        assert_eq!(parse_unit(Some("pt")), SvgUnit::Pt);
+        assert_eq!(parse_unit(Some("mm")), SvgUnit::Mm);
+        assert_eq!(parse_unit(Some("cm")), SvgUnit::Cm);
+        assert_eq!(parse_unit(Some("in")), SvgUnit::In);
+    }
+
+    #[test]
+    fn defaults_unit_to_px() {
+        assert_eq!(parse_unit(None), SvgUnit::Px);
+    }
+}
+
+pub fn input<'a>(args: &'a ArgMatches) -> InputFrom<'a> {
+    match args.value_of(KEYS[Key::Input]) {
+        Some("-") | None => InputFrom::Stdin,
+        Some(path)        => InputFrom::File(path),
+    }
+}
+
+pub fn output<'a>(args: &'a ArgMatches) -> OutputTo<'a> {
+    match args.value_of(KEYS[Key::Output]) {
+        Some("-") | None => OutputTo::Stdout,
+        Some(path)        => OutputTo::File(path),
+    }
+}
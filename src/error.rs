@@ -0,0 +1,58 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+use std::fmt;
+
+/// A cleaning/parsing error, optionally carrying the lower-level error that
+/// caused it so callers can print the full cause chain.
+#[derive(Debug)]
+pub struct Error {
+    messages: Vec<String>,
+}
+
+impl Error {
+    pub fn new<S: Into<String>>(message: S) -> Error {
+        Error { messages: vec![message.into()] }
+    }
+
+    pub fn caused_by<S: Into<String>>(mut self, message: S) -> Error {
+        self.messages.push(message.into());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.messages[0])
+    }
+}
+
+/// Prints the full "caused by" chain of an error, not just its top message.
+pub trait ChainedErrorExt {
+    fn full_chain(&self) -> String;
+}
+
+impl ChainedErrorExt for Error {
+    fn full_chain(&self) -> String {
+        self.messages.join(" -> caused by: ")
+    }
+}
@@ -135,7 +135,11 @@ fn main() {
 
         // Clean document.
         match cleaner::clean_doc(&mut doc, &cleaning_opt, &write_opt) {
-            Ok(_) => {}
+            Ok(warnings) => {
+                for w in &warnings {
+                    writeln!(stderr(), "Warning: {}.", w).unwrap();
+                }
+            }
             Err(e) => {
                 writeln!(stderr(), "{}.", e.full_chain()).unwrap();
                 on_err();
@@ -0,0 +1,82 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+use svg_unit::SvgUnit;
+use svg_version::SvgVersion;
+
+/// Options that control how the input is parsed.
+#[derive(Clone, Copy, Debug)]
+pub struct ParseOptions {
+    pub parse_comments: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            parse_comments: false,
+        }
+    }
+}
+
+/// Options that control how the cleaned document is serialized back out.
+#[derive(Clone, Copy, Debug)]
+pub struct WriteOptions {
+    pub use_single_quote: bool,
+    pub indent: i8,
+    /// The SVG profile the output is targeting. Gates emitting a `version`/
+    /// `baseProfile` on the root; see `CleaningOptions::svg_version` for the
+    /// profile-enforcement half of this setting.
+    pub svg_version: SvgVersion,
+    /// The unit the root `width`/`height` are written out in.
+    pub unit: SvgUnit,
+}
+
+impl Default for WriteOptions {
+    fn default() -> WriteOptions {
+        WriteOptions {
+            use_single_quote: false,
+            indent: 4,
+            svg_version: SvgVersion::default(),
+            unit: SvgUnit::default(),
+        }
+    }
+}
+
+/// Options that control which cleaning passes `clean_doc` runs.
+#[derive(Clone, Copy, Debug)]
+pub struct CleaningOptions {
+    pub append_newline: bool,
+    /// The SVG profile the cleaned output must stay valid for. Constructs
+    /// this profile forbids (currently `filter`/`mask` under the Tiny
+    /// profiles) are stripped from `doc` by `clean_doc`, which reports each
+    /// removal as a warning - see `task::utils::enforce_profile`.
+    pub svg_version: SvgVersion,
+}
+
+impl Default for CleaningOptions {
+    fn default() -> CleaningOptions {
+        CleaningOptions {
+            append_newline: false,
+            svg_version: SvgVersion::default(),
+        }
+    }
+}
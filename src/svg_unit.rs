@@ -0,0 +1,105 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+/// The unit root `width`/`height` are written out in. The `viewBox` and all
+/// other coordinates stay in unitless user space.
+///
+/// Borrows cairo's `SvgUnit` concept for its SVG surface: the caller fixes
+/// the user-space unit of the document instead of always getting bare,
+/// unitless numbers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SvgUnit {
+    /// Bare user-space numbers, no unit suffix.
+    Px,
+    Pt,
+    Mm,
+    Cm,
+    In,
+}
+
+impl Default for SvgUnit {
+    fn default() -> SvgUnit {
+        SvgUnit::Px
+    }
+}
+
+impl SvgUnit {
+    /// How many of this unit make up one CSS inch, per the standard
+    /// 96dpi ratios (1in = 96px = 72pt = 2.54cm = 25.4mm).
+    fn per_inch(&self) -> f64 {
+        match *self {
+            SvgUnit::Px => 96.0,
+            SvgUnit::Pt => 72.0,
+            SvgUnit::Mm => 25.4,
+            SvgUnit::Cm => 2.54,
+            SvgUnit::In => 1.0,
+        }
+    }
+
+    /// The attribute suffix to append to the written number, e.g. `"mm"`.
+    /// `Px` is unitless in SVG, so it has none.
+    pub fn suffix(&self) -> &'static str {
+        match *self {
+            SvgUnit::Px => "",
+            SvgUnit::Pt => "pt",
+            SvgUnit::Mm => "mm",
+            SvgUnit::Cm => "cm",
+            SvgUnit::In => "in",
+        }
+    }
+
+    /// Converts a length from user-space pixels into this unit.
+    pub fn from_px(&self, px: f64) -> f64 {
+        px / SvgUnit::Px.per_inch() * self.per_inch()
+    }
+
+    /// Converts a length expressed in this unit back into user-space pixels.
+    pub fn to_px(&self, value: f64) -> f64 {
+        value / self.per_inch() * SvgUnit::Px.per_inch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn px_to_in() {
+        assert_eq!(SvgUnit::In.from_px(96.0), 1.0);
+    }
+
+    #[test]
+    fn px_to_mm() {
+        assert_eq!(SvgUnit::Mm.from_px(96.0), 25.4);
+    }
+
+    #[test]
+    fn px_to_pt() {
+        assert_eq!(SvgUnit::Pt.from_px(96.0), 72.0);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let px = 123.456;
+        assert!((SvgUnit::Cm.to_px(SvgUnit::Cm.from_px(px)) - px).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,123 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+/// A target SVG profile the cleaner output must stay valid for.
+///
+/// Mirrors cairo's `SvgVersion` for its SVG surface: output is pinned to one
+/// concrete version/profile instead of silently mixing features from all of
+/// them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SvgVersion {
+    /// SVG 1.1 Full. All optimizations are allowed.
+    V1_1,
+    /// SVG 1.1 Tiny. Cleaning strips `filter`/`mask` references, which the
+    /// Tiny profile doesn't define; other Tiny restrictions are not enforced.
+    V1_1Tiny,
+    /// SVG 1.2 Full.
+    V1_2,
+    /// SVG 1.2 Tiny.
+    V1_2Tiny,
+}
+
+impl Default for SvgVersion {
+    fn default() -> SvgVersion {
+        SvgVersion::V1_1
+    }
+}
+
+impl SvgVersion {
+    /// Checks whether this profile allows SVG 1.2-only constructs.
+    pub fn is_1_2(&self) -> bool {
+        match *self {
+            SvgVersion::V1_2 | SvgVersion::V1_2Tiny => true,
+            SvgVersion::V1_1 | SvgVersion::V1_1Tiny => false,
+        }
+    }
+
+    /// Checks whether this profile is a Tiny one, which forbids a chunk
+    /// of Full-only features regardless of the major version.
+    pub fn is_tiny(&self) -> bool {
+        match *self {
+            SvgVersion::V1_1Tiny | SvgVersion::V1_2Tiny => true,
+            SvgVersion::V1_1 | SvgVersion::V1_2 => false,
+        }
+    }
+
+    /// The `version` attribute value to declare on the root `<svg>`, if any.
+    ///
+    /// SVG 1.1 is implied when the attribute is absent, so we only emit it
+    /// for 1.2, where it's meaningful.
+    pub fn version_attr(&self) -> Option<&'static str> {
+        if self.is_1_2() { Some("1.2") } else { None }
+    }
+
+    /// The `baseProfile` attribute value to declare on the root `<svg>`,
+    /// if any. Only meaningful for the Tiny profiles.
+    pub fn base_profile_attr(&self) -> Option<&'static str> {
+        if self.is_tiny() { Some("tiny") } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v1_1_is_neither_1_2_nor_tiny() {
+        assert!(!SvgVersion::V1_1.is_1_2());
+        assert!(!SvgVersion::V1_1.is_tiny());
+    }
+
+    #[test]
+    fn v1_1_tiny_is_tiny_not_1_2() {
+        assert!(!SvgVersion::V1_1Tiny.is_1_2());
+        assert!(SvgVersion::V1_1Tiny.is_tiny());
+    }
+
+    #[test]
+    fn v1_2_is_1_2_not_tiny() {
+        assert!(SvgVersion::V1_2.is_1_2());
+        assert!(!SvgVersion::V1_2.is_tiny());
+    }
+
+    #[test]
+    fn v1_2_tiny_is_both() {
+        assert!(SvgVersion::V1_2Tiny.is_1_2());
+        assert!(SvgVersion::V1_2Tiny.is_tiny());
+    }
+
+    #[test]
+    fn version_attr_only_declared_for_1_2() {
+        assert_eq!(SvgVersion::V1_1.version_attr(), None);
+        assert_eq!(SvgVersion::V1_1Tiny.version_attr(), None);
+        assert_eq!(SvgVersion::V1_2.version_attr(), Some("1.2"));
+        assert_eq!(SvgVersion::V1_2Tiny.version_attr(), Some("1.2"));
+    }
+
+    #[test]
+    fn base_profile_attr_only_declared_for_tiny() {
+        assert_eq!(SvgVersion::V1_1.base_profile_attr(), None);
+        assert_eq!(SvgVersion::V1_2.base_profile_attr(), None);
+        assert_eq!(SvgVersion::V1_1Tiny.base_profile_attr(), Some("tiny"));
+        assert_eq!(SvgVersion::V1_2Tiny.base_profile_attr(), Some("tiny"));
+    }
+}
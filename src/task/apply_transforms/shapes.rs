@@ -23,8 +23,8 @@
 use task::short::{EId, AId};
 use super::utils;
 
-use svgdom::{Document, Node, Attributes};
-use svgdom::types::Transform;
+use svgdom::{Document, Node, Attributes, AttributeValue};
+use svgdom::types::{Transform, PathSegment, Points};
 
 pub fn apply_transform_to_shapes(doc: &Document) {
     // If group has transform and contains only valid shapes
@@ -46,7 +46,10 @@ pub fn apply_transform_to_shapes(doc: &Document) {
                   EId::Rect
                 | EId::Circle
                 | EId::Ellipse
-                | EId::Line => true,
+                | EId::Line
+                | EId::Path
+                | EId::Polyline
+                | EId::Polygon => true,
                 _ => false,
             };
 
@@ -87,6 +90,8 @@ pub fn apply_transform_to_shapes(doc: &Document) {
             EId::Circle => process_circle(&node),
             EId::Ellipse => process_ellipse(&node),
             EId::Line => process_line(&node),
+            EId::Path => process_path(&node),
+            EId::Polyline | EId::Polygon => process_points(&node),
             _ => {}
         }
     }
@@ -102,57 +107,193 @@ fn process<F>(node: &Node, func: F)
     }
 
     let ts = utils::get_ts(node);
+    fold_transform(node, &ts, func);
+}
 
+// Applies 'func' using an already-validated, already-fetched 'ts', for
+// callers that had to inspect the transform themselves before deciding how
+// to process the node (e.g. 'process_circle') and would otherwise end up
+// fetching it a second time.
+fn fold_transform<F>(node: &Node, ts: &Transform, func: F)
+    where F : Fn(&mut Attributes, &Transform)
+{
     {
         let mut attrs = node.attributes_mut();
-        func(&mut attrs, &ts);
+        func(&mut attrs, ts);
         attrs.remove(AId::Transform);
     }
 
     if ts.has_scale() {
-        // we must update 'stroke-width' if transform had scale part in it
-        let (sx, _) = ts.get_scale();
-        ::task::utils::recalc_stroke_width(node, sx);
+        // We must update 'stroke-width' if transform had scale part in it.
+        // Non-uniform scale would stretch the stroke itself, so we can't
+        // just pick one of 'sx'/'sy' without corrupting it - keep the
+        // transform-derived matrix out of it in that case.
+        let (sx, sy) = ts.get_scale();
+        if sx == sy {
+            // The stroke width itself can't go negative even if the scale
+            // that produced it (a reflection) did.
+            ::task::utils::recalc_stroke_width(node, sx.abs());
+        }
     }
 }
 
 fn process_rect(node: &Node) {
+    if utils::has_valid_transform(node) && utils::is_valid_attrs(node) && utils::is_valid_coords(node) {
+        let ts = utils::get_ts(node);
+
+        // A rotation/skew moves corners off-axis, and a reflection (either
+        // axis individually negated - 'ts.a'/'ts.d' are the literal x/y
+        // scale factors here since there's no rotation mixed in yet to
+        // check for) flips which corner is the new top-left: neither can be
+        // expressed by just scaling 'width'/'height' in place, so fall back
+        // to mapping each corner explicitly via a <polygon>.
+        if ts.b != 0.0 || ts.c != 0.0 || ts.a < 0.0 || ts.d < 0.0 {
+            if node.has_attribute(AId::Rx) || node.has_attribute(AId::Ry) {
+                // A rounded corner can't survive this without turning the
+                // rect into a path, so keep the transform as is.
+                return;
+            }
+
+            convert_rect_to_polygon(node, &ts);
+            return;
+        }
+    }
+
     process(node, |mut attrs, ts| {
         utils::transform_coords(&mut attrs, AId::X, AId::Y, ts);
 
         if ts.has_scale() {
-            let (sx, _) = ts.get_scale();
+            let (sx, sy) = ts.get_scale();
 
             utils::scale_coord(&mut attrs, AId::Width, &sx);
-            utils::scale_coord(&mut attrs, AId::Height, &sx);
+            utils::scale_coord(&mut attrs, AId::Height, &sy);
 
             utils::scale_coord(&mut attrs, AId::Rx, &sx);
-            utils::scale_coord(&mut attrs, AId::Ry, &sx);
+            utils::scale_coord(&mut attrs, AId::Ry, &sy);
         }
     });
 }
 
+// Turns an axis-aligned rect into the four-point polygon its corners map to
+// under a rotated/skewed transform, which a <rect> alone can't represent.
+fn convert_rect_to_polygon(node: &Node, ts: &Transform) {
+    let (x, y, w, h) = {
+        let attrs = node.attributes();
+        (
+            attrs.get_number(AId::X).unwrap_or(0.0),
+            attrs.get_number(AId::Y).unwrap_or(0.0),
+            attrs.get_number(AId::Width).unwrap_or(0.0),
+            attrs.get_number(AId::Height).unwrap_or(0.0),
+        )
+    };
+
+    let points = Points(vec![
+        transform_point(ts, x, y),
+        transform_point(ts, x + w, y),
+        transform_point(ts, x + w, y + h),
+        transform_point(ts, x, y + h),
+    ]);
+
+    node.set_tag_name(EId::Polygon);
+    node.set_attribute(AId::Points, points);
+
+    for id in &[AId::X, AId::Y, AId::Width, AId::Height, AId::Rx, AId::Ry, AId::Transform] {
+        node.attributes_mut().remove(*id);
+    }
+
+    if ts.has_scale() {
+        let (sx, sy) = ts.get_scale();
+        if sx == sy {
+            ::task::utils::recalc_stroke_width(node, sx.abs());
+        }
+    }
+}
+
 fn process_circle(node: &Node) {
+    if let Some((ts, non_uniform)) = valid_scale_ts(node) {
+        if non_uniform {
+            if ts.b != 0.0 || ts.c != 0.0 {
+                // A rotated/skewed, non-uniformly scaled circle becomes a
+                // tilted ellipse, which an axis-aligned <ellipse> can't
+                // represent - keep the transform as is rather than
+                // emitting an ellipse with the wrong orientation.
+                return;
+            }
+
+            // An axis-aligned <circle> can't keep a single radius once the
+            // scale differs per axis - turn it into an <ellipse> first and
+            // fold the transform we already have in hand, instead of
+            // re-fetching it through 'process_ellipse'.
+            convert_circle_to_ellipse(node);
+            fold_transform(node, &ts, |mut attrs, ts| ellipse_attrs(&mut attrs, ts));
+            return;
+        }
+    }
+
     process(node, |mut attrs, ts| {
         utils::transform_coords(&mut attrs, AId::Cx, AId::Cy, ts);
 
         if ts.has_scale() {
+            // A reflection must not leave 'r' negative.
             let (sx, _) = ts.get_scale();
-            utils::scale_coord(&mut attrs, AId::R, &sx);
+            utils::scale_coord(&mut attrs, AId::R, &sx.abs());
         }
     });
 }
 
-fn process_ellipse(node: &Node) {
-    process(node, |mut attrs, ts| {
-        utils::transform_coords(&mut attrs, AId::Cx, AId::Cy, ts);
+// `None` means the transform isn't valid/applicable at all, in which case
+// the caller should fall through to the regular bail-out path in `process`.
+// Returns the already-fetched 'ts' alongside the uniform/non-uniform verdict
+// so callers that go on to act on it don't have to fetch it again.
+fn valid_scale_ts(node: &Node) -> Option<(Transform, bool)> {
+    if !utils::has_valid_transform(node)
+        || !utils::is_valid_attrs(node)
+        || !utils::is_valid_coords(node)
+    {
+        return None;
+    }
 
-        if ts.has_scale() {
-            let (sx, _) = ts.get_scale();
-            utils::scale_coord(&mut attrs, AId::Rx, &sx);
-            utils::scale_coord(&mut attrs, AId::Ry, &sx);
+    let ts = utils::get_ts(node);
+    if !ts.has_scale() {
+        return Some((ts, false));
+    }
+
+    let (sx, sy) = ts.get_scale();
+    Some((ts, sx != sy))
+}
+
+fn convert_circle_to_ellipse(node: &Node) {
+    let r = node.attributes().get_number(AId::R).unwrap_or(0.0);
+
+    node.set_attribute(AId::Rx, r);
+    node.set_attribute(AId::Ry, r);
+    node.attributes_mut().remove(AId::R);
+
+    node.set_tag_name(EId::Ellipse);
+}
+
+fn process_ellipse(node: &Node) {
+    if utils::has_valid_transform(node) && utils::is_valid_attrs(node) && utils::is_valid_coords(node) {
+        let ts = utils::get_ts(node);
+        if ts.b != 0.0 || ts.c != 0.0 {
+            // A rotated/skewed ellipse can't stay axis-aligned - keep
+            // the transform as is rather than corrupting its orientation.
+            return;
         }
-    });
+    }
+
+    process(node, |mut attrs, ts| ellipse_attrs(&mut attrs, ts));
+}
+
+fn ellipse_attrs(attrs: &mut Attributes, ts: &Transform) {
+    utils::transform_coords(attrs, AId::Cx, AId::Cy, ts);
+
+    if ts.has_scale() {
+        // Reflections must not leave 'rx'/'ry' negative.
+        let (sx, sy) = ts.get_scale();
+        utils::scale_coord(attrs, AId::Rx, &sx.abs());
+        utils::scale_coord(attrs, AId::Ry, &sy.abs());
+    }
 }
 
 fn process_line(node: &Node) {
@@ -162,6 +303,191 @@ fn process_line(node: &Node) {
     });
 }
 
+fn process_path(node: &Node) {
+    process(node, |mut attrs, ts| {
+        // A rotation or a skew makes 'H'/'V' commands unable to keep their
+        // axis-aligned meaning, so they have to become plain 'L' commands.
+        let rotated = ts.b != 0.0 || ts.c != 0.0;
+
+        if let Some(&mut AttributeValue::Path(ref mut path)) = attrs.get_value_mut(AId::D) {
+            let mut cur = (0.0, 0.0);
+            let mut start = (0.0, 0.0);
+
+            for seg in path.0.iter_mut() {
+                if let PathSegment::MoveTo { abs, x, y } = *seg {
+                    start = abs_point(abs, cur, x, y);
+                }
+
+                let (new_seg, next_cur) = transform_segment(ts, rotated, *seg, cur);
+                *seg = new_seg;
+
+                cur = match new_seg {
+                    PathSegment::ClosePath { .. } => start,
+                    _ => next_cur,
+                };
+            }
+        }
+    });
+}
+
+fn process_points(node: &Node) {
+    process(node, |mut attrs, ts| {
+        if let Some(&mut AttributeValue::Points(ref mut points)) = attrs.get_value_mut(AId::Points) {
+            for p in points.0.iter_mut() {
+                *p = transform_point(ts, p.0, p.1);
+            }
+        }
+    });
+}
+
+fn transform_segment(
+    ts: &Transform,
+    rotated: bool,
+    seg: PathSegment,
+    cur: (f64, f64),
+) -> (PathSegment, (f64, f64)) {
+    match seg {
+        PathSegment::MoveTo { abs, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (PathSegment::MoveTo { abs, x, y }, next)
+        }
+        PathSegment::LineTo { abs, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (PathSegment::LineTo { abs, x, y }, next)
+        }
+        PathSegment::HorizontalLineTo { abs, x } => {
+            let y = if abs { cur.1 } else { 0.0 };
+            let next = abs_point(abs, cur, x, y);
+
+            if rotated {
+                let (x, y) = transform_xy(ts, abs, x, y);
+                (PathSegment::LineTo { abs, x, y }, next)
+            } else {
+                let (x, _) = transform_xy(ts, abs, x, y);
+                (PathSegment::HorizontalLineTo { abs, x }, next)
+            }
+        }
+        PathSegment::VerticalLineTo { abs, y } => {
+            let x = if abs { cur.0 } else { 0.0 };
+            let next = abs_point(abs, cur, x, y);
+
+            if rotated {
+                let (x, y) = transform_xy(ts, abs, x, y);
+                (PathSegment::LineTo { abs, x, y }, next)
+            } else {
+                let (_, y) = transform_xy(ts, abs, x, y);
+                (PathSegment::VerticalLineTo { abs, y }, next)
+            }
+        }
+        PathSegment::CurveTo { abs, x1, y1, x2, y2, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (x1, y1) = transform_xy(ts, abs, x1, y1);
+            let (x2, y2) = transform_xy(ts, abs, x2, y2);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (PathSegment::CurveTo { abs, x1, y1, x2, y2, x, y }, next)
+        }
+        PathSegment::SmoothCurveTo { abs, x2, y2, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (x2, y2) = transform_xy(ts, abs, x2, y2);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (PathSegment::SmoothCurveTo { abs, x2, y2, x, y }, next)
+        }
+        PathSegment::Quadratic { abs, x1, y1, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (x1, y1) = transform_xy(ts, abs, x1, y1);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (PathSegment::Quadratic { abs, x1, y1, x, y }, next)
+        }
+        PathSegment::SmoothQuadratic { abs, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (PathSegment::SmoothQuadratic { abs, x, y }, next)
+        }
+        PathSegment::EllipticalArc { abs, rx, ry, x_axis_rotation, large_arc, sweep, x, y } => {
+            let next = abs_point(abs, cur, x, y);
+            let (rx, ry, x_axis_rotation, sweep) =
+                transform_arc(ts, rx, ry, x_axis_rotation, sweep);
+            let (x, y) = transform_xy(ts, abs, x, y);
+            (
+                PathSegment::EllipticalArc { abs, rx, ry, x_axis_rotation, large_arc, sweep, x, y },
+                next,
+            )
+        }
+        PathSegment::ClosePath { abs } => (PathSegment::ClosePath { abs }, cur),
+    }
+}
+
+// Resolves the absolute end point of a segment in the *original*
+// (not yet transformed) coordinate system, so that 'H'/'V' segments
+// further down the path can look up the coordinate they don't carry.
+fn abs_point(abs: bool, cur: (f64, f64), x: f64, y: f64) -> (f64, f64) {
+    if abs { (x, y) } else { (cur.0 + x, cur.1 + y) }
+}
+
+fn transform_xy(ts: &Transform, abs: bool, x: f64, y: f64) -> (f64, f64) {
+    if abs { transform_point(ts, x, y) } else { transform_vector(ts, x, y) }
+}
+
+fn transform_point(ts: &Transform, x: f64, y: f64) -> (f64, f64) {
+    (ts.a * x + ts.c * y + ts.e, ts.b * x + ts.d * y + ts.f)
+}
+
+fn transform_vector(ts: &Transform, dx: f64, dy: f64) -> (f64, f64) {
+    (ts.a * dx + ts.c * dy, ts.b * dx + ts.d * dy)
+}
+
+// Applies the transform's linear part to the arc's implicit ellipse matrix
+// and recovers the new radii/rotation from an eigen decomposition of the
+// resulting matrix. Based on the standard SVG arc transformation technique.
+fn transform_arc(
+    ts: &Transform,
+    rx: f64,
+    ry: f64,
+    x_axis_rotation: f64,
+    sweep: bool,
+) -> (f64, f64, f64, bool) {
+    let phi = x_axis_rotation.to_radians();
+    let (cos_phi, sin_phi) = (phi.cos(), phi.sin());
+
+    // Linear part of the ellipse's implicit matrix: R(phi) * diag(rx, ry).
+    let m11 =  cos_phi * rx;
+    let m12 = -sin_phi * ry;
+    let m21 =  sin_phi * rx;
+    let m22 =  cos_phi * ry;
+
+    // Apply the transform's linear (2x2) part on top of it.
+    let a11 = ts.a * m11 + ts.c * m21;
+    let a12 = ts.a * m12 + ts.c * m22;
+    let a21 = ts.b * m11 + ts.d * m21;
+    let a22 = ts.b * m12 + ts.d * m22;
+
+    // Eigen-decompose A * A^T to recover the new radii and rotation.
+    let p = a11 * a11 + a12 * a12;
+    let q = a11 * a21 + a12 * a22;
+    let r = a21 * a21 + a22 * a22;
+
+    let mean = (p + r) / 2.0;
+    let diff = ((p - r) / 2.0).hypot(q);
+
+    let new_rx = (mean + diff).max(0.0).sqrt();
+    let new_ry = (mean - diff).max(0.0).sqrt();
+    // Equal radii make a circle, whose rotation is arbitrary: report 0
+    // instead of an angle picked by rounding noise in 'q'.
+    let new_rotation = if diff <= mean * 1e-12 {
+        0.0
+    } else {
+        (0.5 * (2.0 * q).atan2(p - r)).to_degrees()
+    };
+
+    // A reflection (negative determinant) flips the arc's sweep direction.
+    let det = ts.a * ts.d - ts.b * ts.c;
+    let new_sweep = if det < 0.0 { !sweep } else { sweep };
+
+    (new_rx, new_ry, new_rotation, new_sweep)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,6 +560,129 @@ mod tests {
 </svg>
 ");
 
+    test!(apply_rect_non_uniform_scale_1,
+"<svg>
+    <rect height='10' width='20' x='10' y='10' transform='scale(2 3)'/>
+</svg>",
+"<svg>
+    <rect height='30' width='40' x='20' y='30'/>
+</svg>
+");
+
+    test!(apply_rect_rotate_1,
+"<svg>
+    <rect height='10' width='20' x='0' y='0' transform='rotate(90)'/>
+</svg>",
+"<svg>
+    <polygon points='0,0 0,20 -10,20 -10,0'/>
+</svg>
+");
+
+    // a rounded rect can't become a polygon without losing its corners
+    test_eq!(keep_rect_round_rotate_1,
+"<svg>
+    <rect height='10' rx='2' ry='2' width='20' x='0' y='0' transform='rotate(90)'/>
+</svg>
+"
+);
+
+    // a single-axis reflection flips which corner is the new top-left,
+    // which plain width/height scaling can't express
+    test!(apply_rect_reflect_1,
+"<svg>
+    <rect height='10' width='10' x='0' y='0' transform='scale(-1 2)'/>
+</svg>",
+"<svg>
+    <polygon points='0,0 -10,0 -10,20 0,20'/>
+</svg>
+");
+
+    // a uniform reflection still has to keep the recalculated stroke-width
+    // positive
+    test!(apply_rect_reflect_uniform_1,
+"<svg>
+    <rect height='10' width='10' x='0' y='0' transform='scale(-2)'/>
+</svg>",
+"<svg>
+    <polygon points='0,0 -20,0 -20,-20 0,-20' stroke-width='2'/>
+</svg>
+");
+
+    // a rounded rect can't become a polygon without losing its corners,
+    // even when the transform is a reflection rather than a rotation
+    test_eq!(keep_rect_round_reflect_1,
+"<svg>
+    <rect height='10' rx='2' ry='2' width='10' x='0' y='0' transform='scale(-1)'/>
+</svg>
+"
+);
+
+    test!(apply_circle_non_uniform_scale_1,
+"<svg>
+    <circle cx='10' cy='10' r='15' transform='scale(2 3)'/>
+</svg>",
+"<svg>
+    <ellipse cx='20' cy='30' rx='30' ry='45'/>
+</svg>
+");
+
+    // a rotated, non-uniformly scaled circle becomes a tilted ellipse,
+    // which an axis-aligned <ellipse> can't represent
+    test_eq!(keep_circle_rotate_non_uniform_scale_1,
+"<svg>
+    <circle cx='0' cy='0' r='10' transform='rotate(45) scale(2 1)'/>
+</svg>
+"
+);
+
+    // ignore circles with invalid coordinates units, same as 'keep_1' below -
+    // the tag must not be renamed to 'ellipse' before bailing out
+    test_eq!(keep_circle_non_uniform_scale_invalid_coords_1,
+"<svg>
+    <circle cx='10in' cy='10' r='15' transform='scale(2 3)'/>
+</svg>
+"
+);
+
+    test!(apply_ellipse_non_uniform_scale_1,
+"<svg>
+    <ellipse cx='10' cy='10' rx='15' ry='5' transform='scale(2 3)'/>
+</svg>",
+"<svg>
+    <ellipse cx='20' cy='30' rx='30' ry='15'/>
+</svg>
+");
+
+    // a reflective non-uniform scale on a circle must not leave a negative
+    // radius on the resulting ellipse
+    test!(apply_circle_reflect_non_uniform_scale_1,
+"<svg>
+    <circle cx='0' cy='0' r='10' transform='scale(-2 3)'/>
+</svg>",
+"<svg>
+    <ellipse cx='0' cy='0' rx='20' ry='30'/>
+</svg>
+");
+
+    // same as above, but on a shape that's already an ellipse
+    test!(apply_ellipse_reflect_non_uniform_scale_1,
+"<svg>
+    <ellipse cx='0' cy='0' rx='10' ry='5' transform='scale(-2 3)'/>
+</svg>",
+"<svg>
+    <ellipse cx='0' cy='0' rx='20' ry='15'/>
+</svg>
+");
+
+    // a rotated/skewed ellipse can't stay axis-aligned, same restriction
+    // as the non-uniformly scaled circle above
+    test_eq!(keep_ellipse_rotate_1,
+"<svg>
+    <ellipse cx='0' cy='0' rx='10' ry='5' transform='rotate(45)'/>
+</svg>
+"
+);
+
     test!(apply_line_1,
 "<svg>
     <line x1='10' x2='10' y1='15' y2='15' transform='translate(10 20) scale(2)'/>
@@ -241,6 +690,113 @@ mod tests {
 "<svg>
     <line stroke-width='2' x1='30' x2='30' y1='50' y2='50'/>
 </svg>
+");
+
+    // a reflection maps both endpoints the same way a rotation would - no
+    // special-casing needed, but previously untested
+    test!(apply_line_reflect_1,
+"<svg>
+    <line x1='10' x2='20' y1='10' y2='20' transform='scale(-1 2)'/>
+</svg>",
+"<svg>
+    <line x1='-10' x2='-20' y1='20' y2='40'/>
+</svg>
+");
+
+    test!(apply_path_1,
+"<svg>
+    <path d='M 10 10 L 20 20 H 30 V 40 Z' transform='translate(10 20) scale(2)'/>
+</svg>",
+"<svg>
+    <path d='M 30 40 L 50 60 H 70 V 100 Z' stroke-width='2'/>
+</svg>
+");
+
+    test!(apply_path_rotate_1,
+"<svg>
+    <path d='M 10 10 H 20' transform='rotate(90)'/>
+</svg>",
+"<svg>
+    <path d='M -10 10 L -10 20'/>
+</svg>
+");
+
+    test!(apply_path_arc_1,
+"<svg>
+    <path d='M 0 0 A 10 5 0 0 1 20 0' transform='scale(2 1)'/>
+</svg>",
+"<svg>
+    <path d='M 0 0 A 20 5 0 0 1 40 0'/>
+</svg>
+");
+
+    // the arc's own x-axis-rotation swaps which radius lines up with which
+    // scale factor before the transform is applied
+    test!(apply_path_arc_rotated_1,
+"<svg>
+    <path d='M 0 0 A 10 5 90 0 1 10 0' transform='scale(2 1)'/>
+</svg>",
+"<svg>
+    <path d='M 0 0 A 10 10 0 0 1 20 0'/>
+</svg>
+");
+
+    // a skewed transform mixes the two radii together instead of just
+    // scaling them independently
+    test!(apply_path_arc_skew_1,
+"<svg>
+    <path d='M 0 0 A 10 10 0 0 1 10 0' transform='matrix(1 0.75 0.75 1 0 0)'/>
+</svg>",
+"<svg>
+    <path d='M 0 0 A 17.5 2.5 45 0 1 10 7.5' stroke-width='1.25'/>
+</svg>
+");
+
+    // a reflection (negative determinant) must flip the sweep flag, or the
+    // arc bulges to the wrong side of the chord
+    test!(apply_path_arc_reflect_1,
+"<svg>
+    <path d='M 0 0 A 10 5 0 0 1 10 0' transform='scale(-1 1)'/>
+</svg>",
+"<svg>
+    <path d='M 0 0 A 10 5 0 0 0 -10 0'/>
+</svg>
+");
+
+    test!(apply_path_non_uniform_scale_1,
+"<svg>
+    <path d='M 10 10 L 20 20' transform='scale(2 3)'/>
+</svg>",
+"<svg>
+    <path d='M 20 30 L 40 60'/>
+</svg>
+");
+
+    test!(apply_polyline_1,
+"<svg>
+    <polyline points='10,10 20,20' transform='translate(10 20) scale(2)'/>
+</svg>",
+"<svg>
+    <polyline points='30,40 50,60' stroke-width='2'/>
+</svg>
+");
+
+    test!(apply_points_non_uniform_scale_1,
+"<svg>
+    <polyline points='10,10 20,20' transform='scale(2 3)'/>
+</svg>",
+"<svg>
+    <polyline points='20,30 40,60'/>
+</svg>
+");
+
+    test!(apply_polygon_1,
+"<svg>
+    <polygon points='10,10 20,10 20,20' transform='translate(10 20) scale(2)'/>
+</svg>",
+"<svg>
+    <polygon points='30,40 50,40 50,60' stroke-width='2'/>
+</svg>
 ");
 
     test!(apply_g_1,
@@ -268,13 +824,53 @@ mod tests {
 "
 );
 
-    // ignore groups processing with invalid transform types
-    // and attributes
-    test_eq!(keep_2,
+    // a non-uniform scale on a group is still a valid, foldable transform -
+    // it gets pushed down to the child and applied same as any other
+    test!(apply_g_non_uniform_scale_1,
 "<svg>
     <g transform='scale(2 3)'>
         <rect height='10' width='10' x='10' y='10'/>
     </g>
+</svg>",
+"<svg>
+    <g>
+        <rect height='30' width='20' x='20' y='30'/>
+    </g>
+</svg>
+");
+
+    // the push-down whitelist also covers path/polyline/polygon children,
+    // not just the basic shapes
+    test!(apply_g_path_1,
+"<svg>
+    <g transform='translate(10 20) scale(2)'>
+        <path d='M 10 10 L 20 20'/>
+    </g>
+</svg>",
+"<svg>
+    <g>
+        <path d='M 30 40 L 50 60' stroke-width='2'/>
+    </g>
+</svg>
+");
+
+    test!(apply_g_polygon_1,
+"<svg>
+    <g transform='translate(10 20) scale(2)'>
+        <polygon points='10,10 20,10 20,20'/>
+    </g>
+</svg>",
+"<svg>
+    <g>
+        <polygon points='30,40 50,40 50,60' stroke-width='2'/>
+    </g>
+</svg>
+");
+
+    // ignore groups processing with invalid attributes (a 'mask' region is
+    // defined against the group's untransformed user space)
+    test_eq!(keep_2,
+"<svg>
     <mask id='m'/>
     <g mask='url(#m)' transform='scale(2)'>
         <rect height='10' width='10' x='10' y='10'/>
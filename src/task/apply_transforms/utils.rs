@@ -0,0 +1,112 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+use svgdom::{Node, Attributes, AttributeValue};
+use svgdom::types::Transform;
+
+use task::short::AId;
+
+const COORD_IDS: &[AId] = &[
+    AId::X, AId::Y, AId::Cx, AId::Cy, AId::R, AId::Rx, AId::Ry,
+    AId::Width, AId::Height, AId::X1, AId::Y1, AId::X2, AId::Y2,
+];
+
+/// Checks that `node`'s `transform` is a single, invertible affine matrix
+/// that can be folded into the node's own geometry.
+///
+/// Translation, uniform/non-uniform scale, rotation and skew are all plain
+/// affine maps and are all foldable this way - what actually can't be
+/// absorbed is a degenerate (zero-determinant) matrix, which collapses the
+/// shape onto a line/point and has no equivalent expressible purely via
+/// coordinates, or a non-finite one from a malformed/overflowing attribute.
+pub fn has_valid_transform(node: &Node) -> bool {
+    if !node.has_attribute(AId::Transform) {
+        return true;
+    }
+
+    let ts = get_ts(node);
+
+    let finite = ts.a.is_finite() && ts.b.is_finite() && ts.c.is_finite()
+              && ts.d.is_finite() && ts.e.is_finite() && ts.f.is_finite();
+
+    let det = ts.a * ts.d - ts.b * ts.c;
+
+    finite && det != 0.0
+}
+
+/// Checks that folding `node`'s transform into its geometry wouldn't change
+/// how other attributes apply - e.g. a `mask`/`clip-path`/`filter` region is
+/// defined against the element's *untransformed* user space, so baking the
+/// transform into the geometry would shift the shape out from under it.
+pub fn is_valid_attrs(node: &Node) -> bool {
+    !node.has_attribute(AId::Mask)
+        && !node.has_attribute(AId::ClipPath)
+        && !node.has_attribute(AId::Filter)
+}
+
+/// Checks that every coordinate/length attribute `node` actually has is a
+/// plain, unitless number - e.g. `x='10in'` can't be combined with a
+/// transform-derived offset without a unit-aware conversion, so such nodes
+/// are left untouched.
+pub fn is_valid_coords(node: &Node) -> bool {
+    let attrs = node.attributes();
+
+    COORD_IDS.iter().all(|id| {
+        match attrs.get_value(*id) {
+            None => true,
+            Some(&AttributeValue::Number(_)) => true,
+            Some(_) => false,
+        }
+    })
+}
+
+/// Returns the already-parsed `transform` matrix of `node`, or the identity
+/// matrix if it has none.
+pub fn get_ts(node: &Node) -> Transform {
+    match node.attributes().get_value(AId::Transform) {
+        Some(&AttributeValue::Transform(ts)) => ts,
+        _ => Transform::default(),
+    }
+}
+
+/// Applies `ts` to the point stored in `(x_id, y_id)`, defaulting either
+/// coordinate to `0` when absent, same as the SVG spec does for `rect`/
+/// `circle`/`ellipse`/`line`.
+pub fn transform_coords(attrs: &mut Attributes, x_id: AId, y_id: AId, ts: &Transform) {
+    let x = attrs.get_number(x_id).unwrap_or(0.0);
+    let y = attrs.get_number(y_id).unwrap_or(0.0);
+
+    let new_x = ts.a * x + ts.c * y + ts.e;
+    let new_y = ts.b * x + ts.d * y + ts.f;
+
+    attrs.insert(x_id, new_x);
+    attrs.insert(y_id, new_y);
+}
+
+/// Scales the attribute at `id` by `factor`, if present. Attributes that
+/// weren't set (e.g. a `rect` without `rx`/`ry`) are left absent rather than
+/// being created from nothing.
+pub fn scale_coord(attrs: &mut Attributes, id: AId, factor: &f64) {
+    if let Some(value) = attrs.get_number(id) {
+        attrs.insert(id, value * factor);
+    }
+}
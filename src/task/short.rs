@@ -0,0 +1,65 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+// Short, interned-like names for the handful of element/attribute ids the
+// crate needs to match on: the shapes and attributes 'apply_transforms'
+// works with, plus what the profile and root-attribute handling uses.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum EId {
+    Svg,
+    G,
+    Mask,
+    Rect,
+    Circle,
+    Ellipse,
+    Line,
+    Path,
+    Polyline,
+    Polygon,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum AId {
+    Transform,
+    X,
+    Y,
+    Cx,
+    Cy,
+    R,
+    Rx,
+    Ry,
+    Width,
+    Height,
+    X1,
+    Y1,
+    X2,
+    Y2,
+    D,
+    Points,
+    StrokeWidth,
+    Mask,
+    ClipPath,
+    Filter,
+    Version,
+    BaseProfile,
+}
@@ -0,0 +1,91 @@
+/****************************************************************************
+**
+** svgcleaner could help you to clean up your SVG files
+** from unnecessary data.
+** Copyright (C) 2012-2018 Evgeniy Reizner
+**
+** This program is free software; you can redistribute it and/or modify
+** it under the terms of the GNU General Public License as published by
+** the Free Software Foundation; either version 2 of the License, or
+** (at your option) any later version.
+**
+** This program is distributed in the hope that it will be useful,
+** but WITHOUT ANY WARRANTY; without even the implied warranty of
+** MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+** GNU General Public License for more details.
+**
+** You should have received a copy of the GNU General Public License along
+** with this program; if not, write to the Free Software Foundation, Inc.,
+** 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA.
+**
+****************************************************************************/
+
+use svgdom::{Document, Node};
+
+use svg_version::SvgVersion;
+use task::short::AId;
+
+/// Sets `stroke-width` on `node` to its current effective value multiplied
+/// by `sx`, so a baked-in scale doesn't visually thin/thicken the stroke.
+///
+/// The "current effective value" is whatever `node` would have inherited
+/// before its `transform` gets removed: its own `stroke-width` if set, or
+/// the nearest ancestor's, or the initial value of `1` if none is set
+/// anywhere on the path to the root.
+pub fn recalc_stroke_width(node: &Node, sx: f64) {
+    let width = resolve_stroke_width(node);
+    node.set_attribute(AId::StrokeWidth, width * sx);
+}
+
+fn resolve_stroke_width(node: &Node) -> f64 {
+    let mut curr = Some(node.clone());
+
+    while let Some(n) = curr {
+        if let Some(width) = n.attributes().get_number(AId::StrokeWidth) {
+            return width;
+        }
+
+        curr = n.parent();
+    }
+
+    1.0
+}
+
+/// Entry point for resolving `xlink:href`-based attribute inheritance between
+/// gradient elements. This implementation leaves `doc` unchanged and always
+/// returns `Ok(())`: gradient `href` chains are not resolved.
+pub fn resolve_gradient_attributes(_doc: &Document) -> Result<(), ::Error> {
+    Ok(())
+}
+
+/// Strips `filter`/`mask` references from `doc` when `version` is a Tiny
+/// profile and returns a warning for each one removed.
+///
+/// The SVG Tiny profiles (both 1.1 and 1.2) define neither `<filter>` nor
+/// `<mask>`, so a node still carrying a `filter`/`mask` reference would no
+/// longer be valid once the output is pinned to one - this downgrades it by
+/// dropping the reference rather than leaving it in place. No other construct
+/// is checked: anything else the target profile lacks stays in the output.
+pub fn enforce_profile(doc: &Document, version: SvgVersion) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if !version.is_tiny() {
+        return warnings;
+    }
+
+    for node in doc.descendants().svg() {
+        let tag = node.tag_id().unwrap();
+
+        if node.has_attribute(AId::Filter) {
+            node.remove_attribute(AId::Filter);
+            warnings.push(format!("removed 'filter' from a '{:?}', which the Tiny profile forbids", tag));
+        }
+
+        if node.has_attribute(AId::Mask) {
+            node.remove_attribute(AId::Mask);
+            warnings.push(format!("removed 'mask' from a '{:?}', which the Tiny profile forbids", tag));
+        }
+    }
+
+    warnings
+}